@@ -0,0 +1,72 @@
+use std::cmp::Ord;
+
+use crate::error::Result as ClResult;
+
+/// Operations a concrete big-number implementation must provide so that
+/// [`super::BigNumber`] can stay a thin facade over whichever backend is
+/// selected at compile time via cargo features.
+///
+/// The method surface mirrors the public `BigNumber` API as it existed when
+/// this crate only supported OpenSSL; see `openssl.rs` for the reference
+/// implementation.
+pub(crate) trait BigNumberBackend: Sized + Ord + Eq {
+    fn new() -> ClResult<Self>;
+
+    /// Clones the value, propagating an error instead of panicking if the
+    /// backend's underlying allocation/reparse can fail (as OpenSSL's can).
+    fn try_clone(&self) -> ClResult<Self>;
+
+    fn generate_prime(size: usize) -> ClResult<Self>;
+    fn generate_safe_prime(size: usize) -> ClResult<Self>;
+    fn is_prime(&self) -> ClResult<bool>;
+
+    fn rand(size: usize) -> ClResult<Self>;
+    fn rand_range(&self) -> ClResult<Self>;
+
+    fn num_bits(&self) -> ClResult<i32>;
+    fn is_bit_set(&self, n: i32) -> ClResult<bool>;
+    fn set_bit(&mut self, n: i32) -> ClResult<()>;
+
+    fn from_u32(n: usize) -> ClResult<Self>;
+    fn from_dec(dec: &str) -> ClResult<Self>;
+    fn from_hex(hex: &str) -> ClResult<Self>;
+    fn from_bytes(bytes: &[u8]) -> ClResult<Self>;
+
+    fn to_dec(&self) -> ClResult<String>;
+    fn to_hex(&self) -> ClResult<String>;
+    fn to_bytes(&self) -> ClResult<Vec<u8>>;
+
+    fn add(&self, a: &Self) -> ClResult<Self>;
+    fn sub(&self, a: &Self) -> ClResult<Self>;
+    fn sqr(&self) -> ClResult<Self>;
+    fn mul(&self, a: &Self) -> ClResult<Self>;
+    fn div(&self, a: &Self) -> ClResult<Self>;
+
+    fn mod_mul(&self, a: &Self, n: &Self) -> ClResult<Self>;
+    fn mod_sub(&self, a: &Self, n: &Self) -> ClResult<Self>;
+    fn mod_exp(&self, a: &Self, b: &Self) -> ClResult<Self>;
+    fn modulus(&self, a: &Self) -> ClResult<Self>;
+    fn exp(&self, a: &Self) -> ClResult<Self>;
+    fn inverse(&self, n: &Self) -> ClResult<Self>;
+
+    fn gcd(a: &Self, b: &Self) -> ClResult<Self>;
+
+    fn add_word(&mut self, w: u32) -> ClResult<()>;
+    fn sub_word(&mut self, w: u32) -> ClResult<()>;
+    fn mul_word(&mut self, w: u32) -> ClResult<()>;
+    fn div_word(&mut self, w: u32) -> ClResult<()>;
+
+    fn set_negative(&self, negative: bool) -> ClResult<Self>;
+    fn is_negative(&self) -> bool;
+
+    fn increment(&self) -> ClResult<Self>;
+    fn decrement(&self) -> ClResult<Self>;
+
+    fn lshift1(&self) -> ClResult<Self>;
+    fn rshift1(&self) -> ClResult<Self>;
+    fn rshift(&self, n: u32) -> ClResult<Self>;
+
+    /// Overwrites the backing representation with zero so secret material
+    /// doesn't linger in memory once a [`super::SecretBigNumber`] is dropped.
+    fn zeroize(&mut self);
+}
@@ -0,0 +1,95 @@
+use super::BigNumber;
+use crate::error::Result as ClResult;
+
+/// Precomputed CRT parameters for fast fixed-exponent modular exponentiation
+/// under a composite modulus `n = p * q` whose factors are known (e.g. to
+/// the issuer during CL signature issuance and proof verification).
+///
+/// Computing `base^exp mod n` via the two half-width exponentiations this
+/// enables is roughly 3-4x faster than a full-width `BigNumber::mod_exp`.
+pub struct CrtModulus {
+    p: BigNumber,
+    q: BigNumber,
+    dp: BigNumber,
+    dq: BigNumber,
+    qinv: BigNumber,
+}
+
+impl CrtModulus {
+    /// Precomputes `dp = exp mod (p-1)`, `dq = exp mod (q-1)` and
+    /// `qinv = q^-1 mod p` for the fixed exponent `exp`. Validates that `p`
+    /// and `q` are prime and coprime.
+    pub fn new(p: &BigNumber, q: &BigNumber, exp: &BigNumber) -> ClResult<CrtModulus> {
+        if !p.is_prime()? || !q.is_prime()? {
+            return Err(err_msg!(
+                InvalidStructure,
+                "CrtModulus requires p and q to be prime"
+            ));
+        }
+        if BigNumber::gcd(p, q)? != BigNumber::from_u32(1)? {
+            return Err(err_msg!(
+                InvalidStructure,
+                "CrtModulus requires p and q to be coprime"
+            ));
+        }
+
+        let p_minus_one = p.decrement()?;
+        let q_minus_one = q.decrement()?;
+        let dp = exp.modulus(&p_minus_one)?;
+        let dq = exp.modulus(&q_minus_one)?;
+        let qinv = q.inverse(p)?;
+
+        Ok(CrtModulus {
+            p: p.try_clone()?,
+            q: q.try_clone()?,
+            dp,
+            dq,
+            qinv,
+        })
+    }
+
+    /// Computes `base^exp mod (p*q)` using the precomputed CRT parameters.
+    pub fn mod_exp_crt(&self, base: &BigNumber) -> ClResult<BigNumber> {
+        let m1 = base.mod_exp(&self.dp, &self.p)?;
+        let m2 = base.mod_exp(&self.dq, &self.q)?;
+        let h = self.qinv.mod_mul(&m1.mod_sub(&m2, &self.p)?, &self.p)?;
+        m2.add(&h.mul(&self.q)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_plain_mod_exp() {
+        let p = BigNumber::from_u32(11).unwrap();
+        let q = BigNumber::from_u32(13).unwrap();
+        let exp = BigNumber::from_u32(7).unwrap();
+        let n = p.mul(&q).unwrap();
+
+        let crt = CrtModulus::new(&p, &q, &exp).unwrap();
+
+        for base_val in [2, 5, 10, 50, 100] {
+            let base = BigNumber::from_u32(base_val).unwrap();
+            let expected = base.mod_exp(&exp, &n).unwrap();
+            assert_eq!(crt.mod_exp_crt(&base).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn rejects_non_coprime_factors() {
+        let p = BigNumber::from_u32(11).unwrap();
+        let q = BigNumber::from_u32(11).unwrap();
+        let exp = BigNumber::from_u32(7).unwrap();
+        assert!(CrtModulus::new(&p, &q, &exp).is_err());
+    }
+
+    #[test]
+    fn rejects_non_prime_factors() {
+        let p = BigNumber::from_u32(12).unwrap();
+        let q = BigNumber::from_u32(13).unwrap();
+        let exp = BigNumber::from_u32(7).unwrap();
+        assert!(CrtModulus::new(&p, &q, &exp).is_err());
+    }
+}
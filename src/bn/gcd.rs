@@ -0,0 +1,106 @@
+use super::BigNumber;
+use crate::error::Result as ClResult;
+
+/// Result of [`BigNumber::ext_gcd`]: `a*coeff_a + b*coeff_b == gcd`.
+#[derive(Debug, Clone)]
+pub struct GcdResult {
+    pub gcd: BigNumber,
+    pub coeff_a: BigNumber,
+    pub coeff_b: BigNumber,
+}
+
+impl BigNumber {
+    /// Extended Euclidean algorithm, returning the Bézout coefficients
+    /// alongside the gcd so callers don't have to recompute a modular
+    /// inverse (or CRT coefficients) with a separate call.
+    ///
+    /// OpenSSL doesn't expose extended GCD through this wrapper's
+    /// primitives, so this runs the iterative extended Euclidean algorithm
+    /// on top of the existing `div`/`mul`/`sub` methods.
+    pub fn ext_gcd(a: &BigNumber, b: &BigNumber) -> ClResult<GcdResult> {
+        let zero = BigNumber::from_u32(0)?;
+
+        let mut old_r = a.try_clone()?;
+        let mut r = b.try_clone()?;
+        let mut old_s = BigNumber::from_u32(1)?;
+        let mut s = BigNumber::from_u32(0)?;
+        let mut old_t = BigNumber::from_u32(0)?;
+        let mut t = BigNumber::from_u32(1)?;
+
+        while r != zero {
+            let q = old_r.div(&r)?;
+
+            let new_r = old_r.sub(&q.mul(&r)?)?;
+            old_r = std::mem::replace(&mut r, new_r);
+
+            let new_s = old_s.sub(&q.mul(&s)?)?;
+            old_s = std::mem::replace(&mut s, new_s);
+
+            let new_t = old_t.sub(&q.mul(&t)?)?;
+            old_t = std::mem::replace(&mut t, new_t);
+        }
+
+        // Normalize so the gcd is always non-negative, matching `BigNumber::gcd`.
+        if old_r.is_negative() {
+            old_r = old_r.set_negative(false)?;
+            old_s = old_s.set_negative(!old_s.is_negative())?;
+            old_t = old_t.set_negative(!old_t.is_negative())?;
+        }
+
+        Ok(GcdResult {
+            gcd: old_r,
+            coeff_a: old_s,
+            coeff_b: old_t,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_bezout(a: &BigNumber, b: &BigNumber) -> GcdResult {
+        let result = BigNumber::ext_gcd(a, b).unwrap();
+        let lhs = a
+            .mul(&result.coeff_a)
+            .unwrap()
+            .add(&b.mul(&result.coeff_b).unwrap())
+            .unwrap();
+        assert_eq!(lhs, result.gcd, "a*coeff_a + b*coeff_b must equal gcd");
+        result
+    }
+
+    #[test]
+    fn matches_known_gcd() {
+        let a = BigNumber::from_u32(270).unwrap();
+        let b = BigNumber::from_u32(192).unwrap();
+        let result = check_bezout(&a, &b);
+        assert_eq!(result.gcd, BigNumber::from_u32(6).unwrap());
+    }
+
+    #[test]
+    fn coprime_inputs_give_gcd_one() {
+        let a = BigNumber::from_u32(41).unwrap();
+        let b = BigNumber::from_u32(7).unwrap();
+        let result = check_bezout(&a, &b);
+        assert_eq!(result.gcd, BigNumber::from_u32(1).unwrap());
+    }
+
+    #[test]
+    fn b_zero_returns_a_as_gcd() {
+        let a = BigNumber::from_u32(17).unwrap();
+        let b = BigNumber::from_u32(0).unwrap();
+        let result = check_bezout(&a, &b);
+        assert_eq!(result.gcd, a);
+        assert_eq!(result.coeff_a, BigNumber::from_u32(1).unwrap());
+        assert_eq!(result.coeff_b, BigNumber::from_u32(0).unwrap());
+    }
+
+    #[test]
+    fn handles_negative_inputs() {
+        let a = BigNumber::from_u32(270).unwrap().set_negative(true).unwrap();
+        let b = BigNumber::from_u32(192).unwrap();
+        let result = check_bezout(&a, &b);
+        assert_eq!(result.gcd, BigNumber::from_u32(6).unwrap());
+    }
+}
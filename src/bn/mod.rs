@@ -0,0 +1,336 @@
+mod backend;
+mod crt;
+mod gcd;
+mod multi_exp;
+mod secret;
+
+#[cfg(feature = "openssl")]
+mod openssl;
+#[cfg(feature = "rust-bignum")]
+mod rust;
+#[cfg(test)]
+mod tests;
+
+use std::cmp::Ordering;
+
+use backend::BigNumberBackend;
+use subtle::{Choice, ConstantTimeEq};
+
+pub use crt::CrtModulus;
+pub use gcd::GcdResult;
+pub use secret::SecretBigNumber;
+#[cfg(feature = "openssl")]
+pub use openssl::ModulusContext;
+
+#[cfg(feature = "openssl")]
+type Backend = openssl::OpensslBigNumber;
+#[cfg(all(feature = "rust-bignum", not(feature = "openssl")))]
+type Backend = rust::RustBigNumber;
+
+#[cfg(feature = "serde")]
+use crate::serializable_crypto_primitive;
+#[cfg(feature = "serde")]
+use crate::serialization::{
+    deserialize_crypto_primitive, serialize_crypto_primitive, SerializableCryptoPrimitive,
+};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::error::Result as ClResult;
+
+/// Arbitrary-precision integer used throughout CL signature math.
+///
+/// This type is a thin facade: the actual arithmetic is provided by
+/// whichever [`backend::BigNumberBackend`] implementation is selected via
+/// cargo features (`openssl` by default, `rust-bignum` for a pure-Rust
+/// alternative). The public API and serde representation are identical
+/// regardless of backend.
+#[derive(Debug)]
+pub struct BigNumber(Backend);
+
+/// Infallible for API convenience (`#[derive(Clone)]` on types that embed a
+/// `BigNumber`, `n.clone()` when a fallible clone isn't practical,
+/// ...). Prefer `BigNumber::try_clone` in hot paths or anywhere a clone
+/// failure should propagate as an error instead of panicking.
+impl Clone for BigNumber {
+    fn clone(&self) -> BigNumber {
+        self.try_clone().expect("failed to clone BigNumber")
+    }
+}
+
+impl BigNumber {
+    pub fn new() -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::new()?))
+    }
+
+    pub fn generate_prime(size: usize) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::generate_prime(size)?))
+    }
+
+    pub fn generate_safe_prime(size: usize) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::generate_safe_prime(size)?))
+    }
+
+    pub fn is_prime(&self) -> ClResult<bool> {
+        self.0.is_prime()
+    }
+
+    pub fn is_safe_prime(&self) -> ClResult<bool> {
+        // according to https://eprint.iacr.org/2003/186.pdf
+        // a safe prime is congruent to 2 mod 3
+
+        // a safe prime satisfies (p-1)/2 is prime. Since a
+        // prime is odd, We just need to divide by 2
+        Ok(
+            self.modulus(&BigNumber::from_u32(3)?)? == BigNumber::from_u32(2)?
+                && self.is_prime()?
+                && self.rshift1()?.is_prime()?,
+        )
+    }
+
+    pub fn rand(size: usize) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::rand(size)?))
+    }
+
+    pub fn rand_range(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.rand_range()?))
+    }
+
+    pub fn num_bits(&self) -> ClResult<i32> {
+        self.0.num_bits()
+    }
+
+    pub fn is_bit_set(&self, n: i32) -> ClResult<bool> {
+        self.0.is_bit_set(n)
+    }
+
+    pub fn set_bit(&mut self, n: i32) -> ClResult<&mut BigNumber> {
+        self.0.set_bit(n)?;
+        Ok(self)
+    }
+
+    pub fn from_u32(n: usize) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::from_u32(n)?))
+    }
+
+    pub fn from_dec(dec: &str) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::from_dec(dec)?))
+    }
+
+    pub fn from_hex(hex: &str) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::from_hex(hex)?))
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::from_bytes(bytes)?))
+    }
+
+    pub fn to_dec(&self) -> ClResult<String> {
+        self.0.to_dec()
+    }
+
+    pub fn to_hex(&self) -> ClResult<String> {
+        self.0.to_hex()
+    }
+
+    pub fn to_bytes(&self) -> ClResult<Vec<u8>> {
+        self.0.to_bytes()
+    }
+
+    pub fn add(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.add(&a.0)?))
+    }
+
+    pub fn sub(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.sub(&a.0)?))
+    }
+
+    pub fn sqr(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.sqr()?))
+    }
+
+    pub fn mul(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.mul(&a.0)?))
+    }
+
+    pub fn mod_mul(&self, a: &BigNumber, n: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.mod_mul(&a.0, &n.0)?))
+    }
+
+    pub fn mod_sub(&self, a: &BigNumber, n: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.mod_sub(&a.0, &n.0)?))
+    }
+
+    pub fn div(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.div(&a.0)?))
+    }
+
+    pub fn gcd(a: &BigNumber, b: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(Backend::gcd(&a.0, &b.0)?))
+    }
+
+    // Question: The *_word APIs seem odd. When the method is already mutating, why return the reference?
+
+    pub fn add_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+        self.0.add_word(w)?;
+        Ok(self)
+    }
+
+    pub fn sub_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+        self.0.sub_word(w)?;
+        Ok(self)
+    }
+
+    pub fn mul_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+        self.0.mul_word(w)?;
+        Ok(self)
+    }
+
+    pub fn div_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+        self.0.div_word(w)?;
+        Ok(self)
+    }
+
+    pub fn mod_exp(&self, a: &BigNumber, b: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.mod_exp(&a.0, &b.0)?))
+    }
+
+    pub fn modulus(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.modulus(&a.0)?))
+    }
+
+    pub fn exp(&self, a: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.exp(&a.0)?))
+    }
+
+    pub fn inverse(&self, n: &BigNumber) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.inverse(&n.0)?))
+    }
+
+    pub fn set_negative(&self, negative: bool) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.set_negative(negative)?))
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    pub fn increment(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.increment()?))
+    }
+
+    pub fn decrement(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.decrement()?))
+    }
+
+    pub fn lshift1(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.lshift1()?))
+    }
+
+    pub fn rshift1(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.rshift1()?))
+    }
+
+    pub fn rshift(&self, n: u32) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.rshift(n)?))
+    }
+
+    ///(a * (1/b mod p) mod p)
+    pub fn mod_div(&self, b: &BigNumber, p: &BigNumber) -> ClResult<BigNumber> {
+        let b1 = &b.inverse(p)?;
+        self.mod_mul(b1, p)
+    }
+
+    // Question: Why does this need to be a Result? When is creating a BigNumber same as another
+    // BigNumber not possible given sufficient memory?
+    pub fn try_clone(&self) -> ClResult<BigNumber> {
+        Ok(BigNumber(self.0.try_clone()?))
+    }
+
+    /// Constant-time equality, for comparing secret values (master secrets,
+    /// blinding factors, private modulus factors) without leaking timing
+    /// information through the derived, variable-time `PartialEq`.
+    ///
+    /// Compares the fixed-width big-endian byte encodings of both numbers,
+    /// padding the shorter one with leading zero bytes so the comparison
+    /// itself doesn't depend on the values' magnitudes. `to_bytes` only
+    /// encodes the magnitude, so the sign is folded in as a leading byte —
+    /// otherwise e.g. `5` and `-5` would compare equal under `ct_eq` while
+    /// disagreeing under `PartialEq`.
+    pub fn ct_eq(&self, other: &BigNumber) -> ClResult<Choice> {
+        let mut a = self.to_bytes()?;
+        let mut b = other.to_bytes()?;
+        let len = a.len().max(b.len());
+        pad_be_zeros(&mut a, len);
+        pad_be_zeros(&mut b, len);
+        a.insert(0, self.is_negative() as u8);
+        b.insert(0, other.is_negative() as u8);
+        Ok(a.ct_eq(&b))
+    }
+
+    /// Scrubs the backing representation; see [`SecretBigNumber`] for the
+    /// safe, `Drop`-integrated way to do this for secret material.
+    pub(crate) fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+fn pad_be_zeros(bytes: &mut Vec<u8>, len: usize) {
+    if bytes.len() < len {
+        let mut padded = vec![0u8; len - bytes.len()];
+        padded.extend_from_slice(bytes);
+        *bytes = padded;
+    }
+}
+
+impl Ord for BigNumber {
+    fn cmp(&self, other: &BigNumber) -> Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl Eq for BigNumber {}
+
+impl PartialOrd for BigNumber {
+    fn partial_cmp(&self, other: &BigNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for BigNumber {
+    fn eq(&self, other: &BigNumber) -> bool {
+        self.0 == other.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl SerializableCryptoPrimitive for BigNumber {
+    fn name() -> &'static str {
+        "BigNumber"
+    }
+
+    fn to_string(&self) -> ClResult<String> {
+        self.to_dec()
+    }
+
+    fn to_bytes(&self) -> ClResult<Vec<u8>> {
+        self.to_bytes()
+    }
+
+    fn from_string(value: &str) -> ClResult<Self> {
+        BigNumber::from_dec(value)
+    }
+
+    fn from_bytes(value: &[u8]) -> ClResult<Self> {
+        BigNumber::from_bytes(value)
+    }
+}
+
+#[cfg(feature = "serde")]
+serializable_crypto_primitive!(BigNumber);
+
+impl Default for BigNumber {
+    fn default() -> BigNumber {
+        BigNumber::from_u32(0).unwrap()
+    }
+}
@@ -0,0 +1,183 @@
+use super::BigNumber;
+use crate::error::Result as ClResult;
+
+/// Below this many bases, the `2^k`-entry subset-product table costs more
+/// to build than a plain loop of independent `mod_exp`/`mod_mul` calls
+/// saves, so `multi_mod_exp` falls back to the naive product.
+const SIMULTANEOUS_EXP_MIN_BASES: usize = 3;
+
+/// Upper bound on how many bases go into a single subset-product table.
+/// The table has `2^k` entries, so without a cap a caller passing, say, a
+/// few dozen bases (e.g. a credential with many attributes) would blow up
+/// memory; instead the bases are processed in windows of this size and the
+/// per-window results are multiplied together.
+const SIMULTANEOUS_EXP_WINDOW: usize = 8;
+
+impl BigNumber {
+    /// Simultaneous multi-exponentiation: computes `prod(bases[i] ^ exps[i]) mod n`
+    /// with one MSB-to-LSB bit scan per window of bases instead of `k`
+    /// independent exponentiations chained together with multiplies. Below
+    /// `SIMULTANEOUS_EXP_MIN_BASES` bases it computes the naive product.
+    ///
+    /// Bases paired with a negative exponent are inverted mod `n` up front
+    /// (mirroring `BigNumber::mod_exp`'s handling of negative exponents), so
+    /// the table below and bit scan only ever deal with magnitudes.
+    ///
+    /// Bases are processed in windows of at most `SIMULTANEOUS_EXP_WINDOW`:
+    /// each window precomputes a table of the products of every subset of
+    /// its bases (`2^k` entries), then for each exponent bit position
+    /// squares the running accumulator and multiplies in the subset whose
+    /// bases have that bit set across their exponents. Window results are
+    /// combined with `mod_mul`.
+    pub fn multi_mod_exp(bases: &[BigNumber], exps: &[BigNumber], n: &BigNumber) -> ClResult<BigNumber> {
+        if bases.len() != exps.len() {
+            return Err(err_msg!(
+                InvalidStructure,
+                "multi_mod_exp requires bases.len() == exps.len()"
+            ));
+        }
+
+        let k = bases.len();
+        if k < SIMULTANEOUS_EXP_MIN_BASES {
+            let mut acc = BigNumber::from_u32(1)?.modulus(n)?;
+            for (base, exp) in bases.iter().zip(exps.iter()) {
+                acc = acc.mod_mul(&base.mod_exp(exp, n)?, n)?;
+            }
+            return Ok(acc);
+        }
+
+        // Fold each negative exponent into its base (base^-e == (base^-1)^e
+        // mod n).
+        let mut eff_bases = Vec::with_capacity(k);
+        let mut abs_exps = Vec::with_capacity(k);
+        for (base, exp) in bases.iter().zip(exps.iter()) {
+            if exp.is_negative() {
+                eff_bases.push(base.inverse(n)?);
+                abs_exps.push(exp.set_negative(false)?);
+            } else {
+                eff_bases.push(base.try_clone()?);
+                abs_exps.push(exp.try_clone()?);
+            }
+        }
+
+        let mut acc = BigNumber::from_u32(1)?.modulus(n)?;
+        for (window_bases, window_exps) in eff_bases
+            .chunks(SIMULTANEOUS_EXP_WINDOW)
+            .zip(abs_exps.chunks(SIMULTANEOUS_EXP_WINDOW))
+        {
+            let window_result = simultaneous_exp_window(window_bases, window_exps, n)?;
+            acc = acc.mod_mul(&window_result, n)?;
+        }
+        Ok(acc)
+    }
+}
+
+/// Straus/Shamir simultaneous exponentiation over a single window of bases
+/// (at most `SIMULTANEOUS_EXP_WINDOW` of them), all exponents non-negative.
+fn simultaneous_exp_window(
+    bases: &[BigNumber],
+    exps: &[BigNumber],
+    n: &BigNumber,
+) -> ClResult<BigNumber> {
+    let k = bases.len();
+
+    // table[mask] = product over i in mask of bases[i], mod n.
+    let table_len = 1usize << k;
+    let mut table = Vec::with_capacity(table_len);
+    table.push(BigNumber::from_u32(1)?.modulus(n)?);
+    for mask in 1..table_len {
+        let lowest = mask.trailing_zeros() as usize;
+        let rest = mask & (mask - 1);
+        table.push(table[rest].mod_mul(&bases[lowest], n)?);
+    }
+
+    let mut max_bits = 0;
+    for exp in exps {
+        max_bits = max_bits.max(exp.num_bits()?);
+    }
+
+    let mut acc = BigNumber::from_u32(1)?.modulus(n)?;
+    for bit in (0..max_bits).rev() {
+        acc = acc.mod_mul(&acc.try_clone()?, n)?;
+
+        let mut mask = 0usize;
+        for (i, exp) in exps.iter().enumerate() {
+            if exp.is_bit_set(bit)? {
+                mask |= 1 << i;
+            }
+        }
+        if mask != 0 {
+            acc = acc.mod_mul(&table[mask], n)?;
+        }
+    }
+    Ok(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn naive_product(bases: &[BigNumber], exps: &[BigNumber], n: &BigNumber) -> BigNumber {
+        let mut acc = BigNumber::from_u32(1).unwrap().modulus(n).unwrap();
+        for (base, exp) in bases.iter().zip(exps.iter()) {
+            acc = acc.mod_mul(&base.mod_exp(exp, n).unwrap(), n).unwrap();
+        }
+        acc
+    }
+
+    #[test]
+    fn matches_naive_product_for_several_bases() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let bases: Vec<BigNumber> = [2, 3, 5, 7, 11]
+            .iter()
+            .map(|b| BigNumber::from_u32(*b).unwrap())
+            .collect();
+        let exps: Vec<BigNumber> = [13, 17, 19, 23, 29]
+            .iter()
+            .map(|e| BigNumber::from_u32(*e).unwrap())
+            .collect();
+
+        let expected = naive_product(&bases, &exps, &n);
+        let actual = BigNumber::multi_mod_exp(&bases, &exps, &n).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn matches_naive_product_with_negative_exponents() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let bases: Vec<BigNumber> = [2, 3, 5, 7]
+            .iter()
+            .map(|b| BigNumber::from_u32(*b).unwrap())
+            .collect();
+        let exps = vec![
+            BigNumber::from_u32(13).unwrap(),
+            BigNumber::from_u32(17).unwrap().set_negative(true).unwrap(),
+            BigNumber::from_u32(19).unwrap(),
+            BigNumber::from_u32(23).unwrap().set_negative(true).unwrap(),
+        ];
+
+        let expected = naive_product(&bases, &exps, &n);
+        let actual = BigNumber::multi_mod_exp(&bases, &exps, &n).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn matches_naive_product_across_multiple_windows() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let bases: Vec<BigNumber> = (2..22).map(|b| BigNumber::from_u32(b).unwrap()).collect();
+        let exps: Vec<BigNumber> = (100..120).map(|e| BigNumber::from_u32(e).unwrap()).collect();
+        assert!(bases.len() > SIMULTANEOUS_EXP_WINDOW);
+
+        let expected = naive_product(&bases, &exps, &n);
+        let actual = BigNumber::multi_mod_exp(&bases, &exps, &n).unwrap();
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn rejects_mismatched_lengths() {
+        let bases = vec![BigNumber::from_u32(2).unwrap()];
+        let exps = vec![BigNumber::from_u32(2).unwrap(), BigNumber::from_u32(3).unwrap()];
+        let n = BigNumber::from_u32(1000000007).unwrap();
+        assert!(BigNumber::multi_mod_exp(&bases, &exps, &n).is_err());
+    }
+}
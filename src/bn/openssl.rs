@@ -6,15 +6,7 @@ use std::cmp::Ordering;
 use openssl::bn::{BigNum, BigNumContext, BigNumContextRef, BigNumRef, MsbOption};
 use openssl::error::ErrorStack;
 
-#[cfg(feature = "serde")]
-use crate::serializable_crypto_primitive;
-#[cfg(feature = "serde")]
-use crate::serialization::{
-    deserialize_crypto_primitive, serialize_crypto_primitive, SerializableCryptoPrimitive,
-};
-#[cfg(feature = "serde")]
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-
+use crate::bn::backend::BigNumberBackend;
 use crate::error::{Error as ClError, Result as ClResult};
 
 thread_local! {
@@ -29,29 +21,36 @@ where
 }
 
 #[derive(Debug)]
-pub struct BigNumber {
+pub(crate) struct OpensslBigNumber {
     openssl_bn: BigNum,
 }
 
-impl BigNumber {
-    pub fn new() -> ClResult<BigNumber> {
+impl BigNumberBackend for OpensslBigNumber {
+    fn new() -> ClResult<OpensslBigNumber> {
         let bn = BigNum::new_secure()?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
+    }
+
+    fn try_clone(&self) -> ClResult<OpensslBigNumber> {
+        // `BigNum` isn't `Clone`, so re-parse its byte representation.
+        let mut bn = BigNum::from_slice(&self.openssl_bn.to_vec())?;
+        bn.set_negative(self.openssl_bn.is_negative());
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn generate_prime(size: usize) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn generate_prime(size: usize) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::generate_prime(&mut bn.openssl_bn, size as i32, false, None, None)?;
         Ok(bn)
     }
 
-    pub fn generate_safe_prime(size: usize) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn generate_safe_prime(size: usize) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::generate_prime(&mut bn.openssl_bn, (size + 1) as i32, true, None, None)?;
         Ok(bn)
     }
 
-    pub fn is_prime(&self) -> ClResult<bool> {
+    fn is_prime(&self) -> ClResult<bool> {
         let prime_len = self.openssl_bn.num_bits() as f32 * core::f32::consts::LOG10_2;
         let checks = prime_len.log2() as i32;
         Ok(with_bn_context(|ctx| {
@@ -59,21 +58,8 @@ impl BigNumber {
         })?)
     }
 
-    pub fn is_safe_prime(&self) -> ClResult<bool> {
-        // according to https://eprint.iacr.org/2003/186.pdf
-        // a safe prime is congruent to 2 mod 3
-
-        // a safe prime satisfies (p-1)/2 is prime. Since a
-        // prime is odd, We just need to divide by 2
-        Ok(
-            self.modulus(&BigNumber::from_u32(3)?)? == BigNumber::from_u32(2)?
-                && self.is_prime()?
-                && self.rshift1()?.is_prime()?,
-        )
-    }
-
-    pub fn rand(size: usize) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn rand(size: usize) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::rand(
             &mut bn.openssl_bn,
             size as i32,
@@ -83,88 +69,88 @@ impl BigNumber {
         Ok(bn)
     }
 
-    pub fn rand_range(&self) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn rand_range(&self) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::rand_range(&self.openssl_bn, &mut bn.openssl_bn)?;
         Ok(bn)
     }
 
-    pub fn num_bits(&self) -> ClResult<i32> {
+    fn num_bits(&self) -> ClResult<i32> {
         Ok(self.openssl_bn.num_bits())
     }
 
-    pub fn is_bit_set(&self, n: i32) -> ClResult<bool> {
+    fn is_bit_set(&self, n: i32) -> ClResult<bool> {
         Ok(self.openssl_bn.is_bit_set(n))
     }
 
-    pub fn set_bit(&mut self, n: i32) -> ClResult<&mut BigNumber> {
+    fn set_bit(&mut self, n: i32) -> ClResult<()> {
         BigNumRef::set_bit(&mut self.openssl_bn, n)?;
-        Ok(self)
+        Ok(())
     }
 
-    pub fn from_u32(n: usize) -> ClResult<BigNumber> {
+    fn from_u32(n: usize) -> ClResult<OpensslBigNumber> {
         let bn = BigNum::from_u32(n as u32)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn from_dec(dec: &str) -> ClResult<BigNumber> {
+    fn from_dec(dec: &str) -> ClResult<OpensslBigNumber> {
         let bn = BigNum::from_dec_str(dec)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn from_hex(hex: &str) -> ClResult<BigNumber> {
+    fn from_hex(hex: &str) -> ClResult<OpensslBigNumber> {
         let bn = BigNum::from_hex_str(hex)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn from_bytes(bytes: &[u8]) -> ClResult<BigNumber> {
+    fn from_bytes(bytes: &[u8]) -> ClResult<OpensslBigNumber> {
         let bn = BigNum::from_slice(bytes)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn to_dec(&self) -> ClResult<String> {
+    fn to_dec(&self) -> ClResult<String> {
         let result = self.openssl_bn.to_dec_str()?;
         Ok(result.to_string())
     }
 
-    pub fn to_hex(&self) -> ClResult<String> {
+    fn to_hex(&self) -> ClResult<String> {
         let result = self.openssl_bn.to_hex_str()?;
         Ok(result.to_string())
     }
 
-    pub fn to_bytes(&self) -> ClResult<Vec<u8>> {
+    fn to_bytes(&self) -> ClResult<Vec<u8>> {
         Ok(self.openssl_bn.to_vec())
     }
 
-    pub fn add(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn add(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::checked_add(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn)?;
         Ok(bn)
     }
 
-    pub fn sub(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn sub(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::checked_sub(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn)?;
         Ok(bn)
     }
 
     // TODO: There should be a mod_sqr using underlying math library's square modulo since squaring is faster.
-    pub fn sqr(&self) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn sqr(&self) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| BigNumRef::sqr(&mut bn.openssl_bn, &self.openssl_bn, ctx))?;
         Ok(bn)
     }
 
-    pub fn mul(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn mul(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::checked_mul(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn, ctx)
         })?;
         Ok(bn)
     }
 
-    pub fn mod_mul(&self, a: &BigNumber, n: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn mod_mul(&self, a: &OpensslBigNumber, n: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::mod_mul(
                 &mut bn.openssl_bn,
@@ -177,8 +163,8 @@ impl BigNumber {
         Ok(bn)
     }
 
-    pub fn mod_sub(&self, a: &BigNumber, n: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn mod_sub(&self, a: &OpensslBigNumber, n: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::mod_sub(
                 &mut bn.openssl_bn,
@@ -191,16 +177,16 @@ impl BigNumber {
         Ok(bn)
     }
 
-    pub fn div(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn div(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::checked_div(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn, ctx)
         })?;
         Ok(bn)
     }
 
-    pub fn gcd(a: &BigNumber, b: &BigNumber) -> ClResult<BigNumber> {
-        let mut gcd = BigNumber::new()?;
+    fn gcd(a: &OpensslBigNumber, b: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut gcd = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::gcd(&mut gcd.openssl_bn, &a.openssl_bn, &b.openssl_bn, ctx)
         })?;
@@ -209,28 +195,28 @@ impl BigNumber {
 
     // Question: The *_word APIs seem odd. When the method is already mutating, why return the reference?
 
-    pub fn add_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+    fn add_word(&mut self, w: u32) -> ClResult<()> {
         BigNumRef::add_word(&mut self.openssl_bn, w)?;
-        Ok(self)
+        Ok(())
     }
 
-    pub fn sub_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+    fn sub_word(&mut self, w: u32) -> ClResult<()> {
         BigNumRef::sub_word(&mut self.openssl_bn, w)?;
-        Ok(self)
+        Ok(())
     }
 
-    pub fn mul_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+    fn mul_word(&mut self, w: u32) -> ClResult<()> {
         BigNumRef::mul_word(&mut self.openssl_bn, w)?;
-        Ok(self)
+        Ok(())
     }
 
-    pub fn div_word(&mut self, w: u32) -> ClResult<&mut BigNumber> {
+    fn div_word(&mut self, w: u32) -> ClResult<()> {
         BigNumRef::div_word(&mut self.openssl_bn, w)?;
-        Ok(self)
+        Ok(())
     }
 
-    pub fn mod_exp(&self, a: &BigNumber, b: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn mod_exp(&self, a: &OpensslBigNumber, b: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
 
         if a.openssl_bn.is_negative() {
             let (base, a1) = (self.inverse(b)?, a.set_negative(false)?);
@@ -257,150 +243,224 @@ impl BigNumber {
         Ok(bn)
     }
 
-    pub fn modulus(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn modulus(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::nnmod(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn, ctx)
         })?;
         Ok(bn)
     }
 
-    pub fn exp(&self, a: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn exp(&self, a: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::exp(&mut bn.openssl_bn, &self.openssl_bn, &a.openssl_bn, ctx)
         })?;
         Ok(bn)
     }
 
-    pub fn inverse(&self, n: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn inverse(&self, n: &OpensslBigNumber) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         with_bn_context(|ctx| {
             BigNumRef::mod_inverse(&mut bn.openssl_bn, &self.openssl_bn, &n.openssl_bn, ctx)
         })?;
         Ok(bn)
     }
 
-    pub fn set_negative(&self, negative: bool) -> ClResult<BigNumber> {
+    fn set_negative(&self, negative: bool) -> ClResult<OpensslBigNumber> {
         let mut bn = BigNum::from_slice(&self.openssl_bn.to_vec())?;
         bn.set_negative(negative);
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn is_negative(&self) -> bool {
+    fn is_negative(&self) -> bool {
         self.openssl_bn.is_negative()
     }
 
-    pub fn increment(&self) -> ClResult<BigNumber> {
+    fn increment(&self) -> ClResult<OpensslBigNumber> {
         let mut bn = BigNum::from_slice(&self.openssl_bn.to_vec())?;
         bn.add_word(1)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn decrement(&self) -> ClResult<BigNumber> {
+    fn decrement(&self) -> ClResult<OpensslBigNumber> {
         let mut bn = BigNum::from_slice(&self.openssl_bn.to_vec())?;
         bn.sub_word(1)?;
-        Ok(BigNumber { openssl_bn: bn })
+        Ok(OpensslBigNumber { openssl_bn: bn })
     }
 
-    pub fn lshift1(&self) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn lshift1(&self) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::lshift1(&mut bn.openssl_bn, &self.openssl_bn)?;
         Ok(bn)
     }
 
-    pub fn rshift1(&self) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn rshift1(&self) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::rshift1(&mut bn.openssl_bn, &self.openssl_bn)?;
         Ok(bn)
     }
 
-    pub fn rshift(&self, n: u32) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
+    fn rshift(&self, n: u32) -> ClResult<OpensslBigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
         BigNumRef::rshift(&mut bn.openssl_bn, &self.openssl_bn, n as i32)?;
         Ok(bn)
     }
 
-    ///(a * (1/b mod p) mod p)
-    pub fn mod_div(&self, b: &BigNumber, p: &BigNumber) -> ClResult<BigNumber> {
-        let mut bn = BigNumber::new()?;
-        let b1 = &b.inverse(p)?;
-        with_bn_context(|ctx| {
-            BigNumRef::mod_mul(
-                &mut bn.openssl_bn,
-                &self.openssl_bn,
-                &b1.openssl_bn,
-                &p.openssl_bn,
-                ctx,
-            )
-        })?;
-        Ok(bn)
-    }
-
-    // Question: Why does this need to be a Result? When is creating a BigNumber same as another
-    // BigNumber not possible given sufficient memory?
-    pub fn try_clone(&self) -> ClResult<BigNumber> {
-        let mut openssl_bn = BigNum::from_slice(&self.openssl_bn.to_vec()[..])?;
-        openssl_bn.set_negative(self.is_negative());
-        Ok(BigNumber { openssl_bn })
+    fn zeroize(&mut self) {
+        // `BN_clear` scrubs the digit buffer in place regardless of which
+        // heap it was allocated from, unlike swapping in a fresh
+        // `new_secure` BigNum (most constructors here — `from_u32`,
+        // `from_dec`, `from_hex`, `from_bytes` — go through plain `BN_new`,
+        // not the secure allocator, so that wouldn't actually guarantee
+        // anything).
+        self.openssl_bn.clear();
     }
 }
 
-impl Ord for BigNumber {
-    fn cmp(&self, other: &BigNumber) -> Ordering {
+impl Ord for OpensslBigNumber {
+    fn cmp(&self, other: &OpensslBigNumber) -> Ordering {
         self.openssl_bn.cmp(&other.openssl_bn)
     }
 }
 
-impl Eq for BigNumber {}
+impl Eq for OpensslBigNumber {}
 
-impl PartialOrd for BigNumber {
-    fn partial_cmp(&self, other: &BigNumber) -> Option<Ordering> {
+impl PartialOrd for OpensslBigNumber {
+    fn partial_cmp(&self, other: &OpensslBigNumber) -> Option<Ordering> {
         Some(self.cmp(other))
     }
 }
 
-impl PartialEq for BigNumber {
-    fn eq(&self, other: &BigNumber) -> bool {
+impl PartialEq for OpensslBigNumber {
+    fn eq(&self, other: &OpensslBigNumber) -> bool {
         self.openssl_bn == other.openssl_bn
     }
 }
 
-#[cfg(feature = "serde")]
-impl SerializableCryptoPrimitive for BigNumber {
-    fn name() -> &'static str {
-        "BigNumber"
+impl From<ErrorStack> for ClError {
+    fn from(err: ErrorStack) -> Self {
+        // TODO: FIXME: Analyze ErrorStack and split invalid structure errors from other errors
+        err_msg!(InvalidState, "Internal OpenSSL error: {}", err)
     }
+}
 
-    fn to_string(&self) -> ClResult<String> {
-        self.to_dec()
+impl Default for OpensslBigNumber {
+    fn default() -> OpensslBigNumber {
+        OpensslBigNumber::from_u32(0).unwrap()
     }
+}
 
-    fn to_bytes(&self) -> ClResult<Vec<u8>> {
-        self.to_bytes()
-    }
+/// A per-modulus `BigNumContext` for `mod_exp`/`mod_mul` calls against a
+/// single fixed modulus.
+///
+/// This is *not* a precomputed Montgomery reduction context: the `openssl`
+/// crate's safe bindings don't expose `BN_MONT_CTX`, so there is no real
+/// Montgomery precomputation here, and no speedup over the existing
+/// thread-local `BigNumContext` (which is itself already allocated once per
+/// thread and reused across calls). What this does provide is an owned
+/// scratch context scoped to one modulus/public key, instead of the single
+/// context shared thread-wide, for callers that want that separation.
+/// Replace this with real Montgomery precomputation (e.g. via
+/// `openssl-sys` FFI to `BN_MONT_CTX_*`) if/when that lands as safe API.
+pub struct ModulusContext {
+    n: OpensslBigNumber,
+    ctx: RefCell<BigNumContext>,
+}
 
-    fn from_string(value: &str) -> ClResult<Self> {
-        BigNumber::from_dec(value)
+impl ModulusContext {
+    pub fn new(n: &crate::bn::BigNumber) -> ClResult<ModulusContext> {
+        Ok(ModulusContext {
+            n: n.0.try_clone()?,
+            ctx: RefCell::new(BigNumContext::new_secure()?),
+        })
+    }
+
+    pub fn mod_exp(
+        &self,
+        base: &crate::bn::BigNumber,
+        exp: &crate::bn::BigNumber,
+    ) -> ClResult<crate::bn::BigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
+        self.with_ctx(|ctx| {
+            BigNumRef::mod_exp(
+                &mut bn.openssl_bn,
+                &base.0.openssl_bn,
+                &exp.0.openssl_bn,
+                &self.n.openssl_bn,
+                ctx,
+            )
+        })?;
+        Ok(crate::bn::BigNumber(bn))
     }
 
-    fn from_bytes(value: &[u8]) -> ClResult<Self> {
-        BigNumber::from_bytes(value)
+    pub fn mod_mul(
+        &self,
+        a: &crate::bn::BigNumber,
+        b: &crate::bn::BigNumber,
+    ) -> ClResult<crate::bn::BigNumber> {
+        let mut bn = OpensslBigNumber::new()?;
+        self.with_ctx(|ctx| {
+            BigNumRef::mod_mul(
+                &mut bn.openssl_bn,
+                &a.0.openssl_bn,
+                &b.0.openssl_bn,
+                &self.n.openssl_bn,
+                ctx,
+            )
+        })?;
+        Ok(crate::bn::BigNumber(bn))
     }
-}
 
-#[cfg(feature = "serde")]
-serializable_crypto_primitive!(BigNumber);
-
-impl From<ErrorStack> for ClError {
-    fn from(err: ErrorStack) -> Self {
-        // TODO: FIXME: Analyze ErrorStack and split invalid structure errors from other errors
-        err_msg!(InvalidState, "Internal OpenSSL error: {}", err)
+    fn with_ctx<F, R>(&self, f: F) -> R
+    where
+        F: FnOnce(&mut BigNumContextRef) -> R,
+    {
+        f(self.ctx.borrow_mut().borrow_mut())
     }
 }
 
-impl Default for BigNumber {
-    fn default() -> BigNumber {
-        BigNumber::from_u32(0).unwrap()
+#[cfg(test)]
+mod modulus_context_tests {
+    use super::*;
+    use crate::bn::BigNumber;
+
+    #[test]
+    fn mod_exp_matches_plain_mod_exp() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let ctx = ModulusContext::new(&n).unwrap();
+
+        for (base, exp) in [(2u32, 13u32), (5, 1000), (1000000006, 2)] {
+            let base = BigNumber::from_u32(base as usize).unwrap();
+            let exp = BigNumber::from_u32(exp as usize).unwrap();
+            let expected = base.mod_exp(&exp, &n).unwrap();
+            assert_eq!(ctx.mod_exp(&base, &exp).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn mod_mul_matches_plain_mod_mul() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let ctx = ModulusContext::new(&n).unwrap();
+
+        for (a, b) in [(2u32, 3u32), (500000000, 999999999), (1, 1000000006)] {
+            let a = BigNumber::from_u32(a as usize).unwrap();
+            let b = BigNumber::from_u32(b as usize).unwrap();
+            let expected = a.mod_mul(&b, &n).unwrap();
+            assert_eq!(ctx.mod_mul(&a, &b).unwrap(), expected);
+        }
+    }
+
+    #[test]
+    fn reuse_across_calls_does_not_corrupt_state() {
+        let n = BigNumber::from_dec("1000000007").unwrap();
+        let ctx = ModulusContext::new(&n).unwrap();
+        let base = BigNumber::from_u32(3).unwrap();
+        let exp = BigNumber::from_u32(5).unwrap();
+
+        let first = ctx.mod_exp(&base, &exp).unwrap();
+        let second = ctx.mod_exp(&base, &exp).unwrap();
+        assert_eq!(first, second);
+        assert_eq!(first, base.mod_exp(&exp, &n).unwrap());
     }
 }
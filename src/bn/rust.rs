@@ -0,0 +1,385 @@
+use std::cmp::Ordering;
+
+use num_bigint::{BigInt, BigUint, RandBigInt, Sign};
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+use rand::thread_rng;
+use zeroize::Zeroize;
+
+use crate::bn::backend::BigNumberBackend;
+use crate::error::Result as ClResult;
+
+/// Pure-Rust `BigNumberBackend`, built on `num-bigint`, for targets where
+/// linking OpenSSL is impractical (WASM, static musl, some mobile builds).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct RustBigNumber {
+    value: BigInt,
+}
+
+impl RustBigNumber {
+    fn from_value(value: BigInt) -> RustBigNumber {
+        RustBigNumber { value }
+    }
+
+    /// Miller-Rabin primality test with a number of rounds scaled to the
+    /// input size, matching the confidence level OpenSSL's
+    /// `is_prime_fasttest` targets.
+    fn miller_rabin(n: &BigInt, rounds: u32) -> bool {
+        if *n < BigInt::from(2) {
+            return false;
+        }
+        let two = BigInt::from(2);
+        if *n == two || *n == BigInt::from(3) {
+            return true;
+        }
+        if (n % &two).is_zero() {
+            return false;
+        }
+
+        let n_minus_one = n - BigInt::one();
+        let mut d = n_minus_one.clone();
+        let mut r: u32 = 0;
+        while (&d % &two).is_zero() {
+            d /= &two;
+            r += 1;
+        }
+
+        let mut rng = thread_rng();
+        'witness: for _ in 0..rounds {
+            let a = rng.gen_bigint_range(&two, &(n - &two));
+            let mut x = a.modpow(&d, n);
+            if x == BigInt::one() || x == n_minus_one {
+                continue;
+            }
+            for _ in 0..r - 1 {
+                x = x.modpow(&two, n);
+                if x == n_minus_one {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+impl BigNumberBackend for RustBigNumber {
+    fn new() -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(BigInt::zero()))
+    }
+
+    fn try_clone(&self) -> ClResult<RustBigNumber> {
+        // `BigInt::clone` can only fail via allocation failure, same as any
+        // other `Clone` impl, so this never actually returns `Err`.
+        Ok(self.clone())
+    }
+
+    fn generate_prime(size: usize) -> ClResult<RustBigNumber> {
+        let mut rng = thread_rng();
+        loop {
+            let mut candidate = rng.gen_biguint(size as u64);
+            candidate.set_bit(size as u64 - 1, true);
+            candidate.set_bit(0, true);
+            let candidate = BigInt::from_biguint(Sign::Plus, candidate);
+            if RustBigNumber::miller_rabin(&candidate, 64) {
+                return Ok(RustBigNumber::from_value(candidate));
+            }
+        }
+    }
+
+    fn generate_safe_prime(size: usize) -> ClResult<RustBigNumber> {
+        loop {
+            let candidate = RustBigNumber::generate_prime(size + 1)?.value;
+            let half = (&candidate - BigInt::one()) / BigInt::from(2);
+            if RustBigNumber::miller_rabin(&half, 64) {
+                return Ok(RustBigNumber::from_value(candidate));
+            }
+        }
+    }
+
+    fn is_prime(&self) -> ClResult<bool> {
+        let bits = self.value.bits() as f32;
+        let prime_len = bits * core::f32::consts::LOG10_2;
+        let checks = (prime_len.log2() as u32).max(1);
+        Ok(RustBigNumber::miller_rabin(&self.value, checks))
+    }
+
+    fn rand(size: usize) -> ClResult<RustBigNumber> {
+        let mut rng = thread_rng();
+        let value = rng.gen_biguint(size as u64);
+        Ok(RustBigNumber::from_value(BigInt::from_biguint(
+            Sign::Plus,
+            value,
+        )))
+    }
+
+    fn rand_range(&self) -> ClResult<RustBigNumber> {
+        let mut rng = thread_rng();
+        let value = rng.gen_bigint_range(&BigInt::zero(), &self.value);
+        Ok(RustBigNumber::from_value(value))
+    }
+
+    fn num_bits(&self) -> ClResult<i32> {
+        Ok(self.value.bits() as i32)
+    }
+
+    fn is_bit_set(&self, n: i32) -> ClResult<bool> {
+        let (_, bytes) = self.value.to_bytes_le();
+        let byte = (n / 8) as usize;
+        let bit = (n % 8) as u32;
+        Ok(bytes.get(byte).map(|b| (b >> bit) & 1 == 1).unwrap_or(false))
+    }
+
+    fn set_bit(&mut self, n: i32) -> ClResult<()> {
+        self.value |= BigInt::one() << n;
+        Ok(())
+    }
+
+    fn from_u32(n: usize) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(BigInt::from(n as u64)))
+    }
+
+    fn from_dec(dec: &str) -> ClResult<RustBigNumber> {
+        let value = dec
+            .parse::<BigInt>()
+            .map_err(|e| err_msg!(InvalidStructure, "Invalid decimal BigNumber: {}", e))?;
+        Ok(RustBigNumber::from_value(value))
+    }
+
+    fn from_hex(hex: &str) -> ClResult<RustBigNumber> {
+        let (sign, digits) = match hex.strip_prefix('-') {
+            Some(rest) => (Sign::Minus, rest),
+            None => (Sign::Plus, hex),
+        };
+        let value = BigInt::parse_bytes(digits.as_bytes(), 16)
+            .ok_or_else(|| err_msg!(InvalidStructure, "Invalid hex BigNumber: {}", hex))?;
+        Ok(RustBigNumber::from_value(value * sign_multiplier(sign)))
+    }
+
+    fn from_bytes(bytes: &[u8]) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(BigInt::from_bytes_be(
+            Sign::Plus,
+            bytes,
+        )))
+    }
+
+    fn to_dec(&self) -> ClResult<String> {
+        Ok(self.value.to_str_radix(10))
+    }
+
+    fn to_hex(&self) -> ClResult<String> {
+        // OpenSSL's `BN_bn2hex` writes whole bytes, so an odd number of
+        // nibbles gets a leading `0` (zero itself is written as `0`).
+        let mut hex = self.value.magnitude().to_str_radix(16).to_uppercase();
+        if hex.len() % 2 == 1 && !self.value.is_zero() {
+            hex.insert(0, '0');
+        }
+        if self.value.is_negative() {
+            hex.insert(0, '-');
+        }
+        Ok(hex)
+    }
+
+    fn to_bytes(&self) -> ClResult<Vec<u8>> {
+        // `num-bigint` encodes zero as `[0]`; OpenSSL encodes it as no bytes.
+        if self.value.is_zero() {
+            return Ok(Vec::new());
+        }
+        Ok(self.value.to_bytes_be().1)
+    }
+
+    fn add(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value + &a.value))
+    }
+
+    fn sub(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value - &a.value))
+    }
+
+    fn sqr(&self) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value * &self.value))
+    }
+
+    fn mul(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value * &a.value))
+    }
+
+    fn div(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value / &a.value))
+    }
+
+    fn mod_mul(&self, a: &RustBigNumber, n: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(
+            (&self.value * &a.value).mod_floor(&n.value),
+        ))
+    }
+
+    fn mod_sub(&self, a: &RustBigNumber, n: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(
+            (&self.value - &a.value).mod_floor(&n.value),
+        ))
+    }
+
+    fn mod_exp(&self, a: &RustBigNumber, b: &RustBigNumber) -> ClResult<RustBigNumber> {
+        if a.value.is_negative() {
+            let base = self.inverse(b)?;
+            let exp = -&a.value;
+            Ok(RustBigNumber::from_value(base.value.modpow(&exp, &b.value)))
+        } else {
+            Ok(RustBigNumber::from_value(
+                self.value.modpow(&a.value, &b.value),
+            ))
+        }
+    }
+
+    fn modulus(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(self.value.mod_floor(&a.value)))
+    }
+
+    fn exp(&self, a: &RustBigNumber) -> ClResult<RustBigNumber> {
+        // `BigInt::pow` only takes a `u32`, which would silently truncate
+        // any exponent that doesn't fit in a word, so square-and-multiply
+        // over the full-width exponent by hand instead.
+        let mut exp = a
+            .value
+            .to_biguint()
+            .ok_or_else(|| err_msg!(InvalidStructure, "exp requires a non-negative exponent"))?;
+        let two = BigUint::from(2u32);
+
+        let mut result = BigInt::one();
+        let mut base = self.value.clone();
+        while !exp.is_zero() {
+            if (&exp % &two).is_one() {
+                result *= &base;
+            }
+            base = &base * &base;
+            exp /= &two;
+        }
+        Ok(RustBigNumber::from_value(result))
+    }
+
+    fn inverse(&self, n: &RustBigNumber) -> ClResult<RustBigNumber> {
+        let (gcd, x, _) = ext_gcd(&self.value, &n.value);
+        if gcd != BigInt::one() {
+            return Err(err_msg!(
+                InvalidStructure,
+                "No modular inverse exists for the given modulus"
+            ));
+        }
+        Ok(RustBigNumber::from_value(x.mod_floor(&n.value)))
+    }
+
+    fn gcd(a: &RustBigNumber, b: &RustBigNumber) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(a.value.gcd(&b.value)))
+    }
+
+    fn add_word(&mut self, w: u32) -> ClResult<()> {
+        self.value += BigInt::from(w);
+        Ok(())
+    }
+
+    fn sub_word(&mut self, w: u32) -> ClResult<()> {
+        self.value -= BigInt::from(w);
+        Ok(())
+    }
+
+    fn mul_word(&mut self, w: u32) -> ClResult<()> {
+        self.value *= BigInt::from(w);
+        Ok(())
+    }
+
+    fn div_word(&mut self, w: u32) -> ClResult<()> {
+        self.value /= BigInt::from(w);
+        Ok(())
+    }
+
+    fn set_negative(&self, negative: bool) -> ClResult<RustBigNumber> {
+        let mut value = self.value.clone();
+        value = value.abs();
+        if negative {
+            value = -value;
+        }
+        Ok(RustBigNumber::from_value(value))
+    }
+
+    fn is_negative(&self) -> bool {
+        self.value.is_negative()
+    }
+
+    fn increment(&self) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value + BigInt::one()))
+    }
+
+    fn decrement(&self) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value - BigInt::one()))
+    }
+
+    fn lshift1(&self) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value << 1))
+    }
+
+    fn rshift1(&self) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value >> 1))
+    }
+
+    fn rshift(&self, n: u32) -> ClResult<RustBigNumber> {
+        Ok(RustBigNumber::from_value(&self.value >> n))
+    }
+
+    fn zeroize(&mut self) {
+        // `num-bigint` doesn't expose a safe way to reach into its internal
+        // digit buffer, so unlike the OpenSSL backend (which clears its
+        // digit buffer in place with `BN_clear`), the old allocation itself
+        // is not wiped before the ordinary allocator reclaims it. Only the
+        // digit copy taken from the old value is zeroed here; the old value
+        // is then dropped as is.
+        let old = std::mem::replace(&mut self.value, BigInt::zero());
+        let (_, mut digits) = old.to_u32_digits();
+        digits.zeroize();
+    }
+}
+
+impl Ord for RustBigNumber {
+    fn cmp(&self, other: &RustBigNumber) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+}
+
+impl PartialOrd for RustBigNumber {
+    fn partial_cmp(&self, other: &RustBigNumber) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Default for RustBigNumber {
+    fn default() -> RustBigNumber {
+        RustBigNumber::from_value(BigInt::zero())
+    }
+}
+
+fn sign_multiplier(sign: Sign) -> BigInt {
+    match sign {
+        Sign::Minus => BigInt::from(-1),
+        _ => BigInt::one(),
+    }
+}
+
+/// Iterative extended Euclidean algorithm, used by `inverse` since
+/// `num-bigint` has no modular inverse of its own.
+fn ext_gcd(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    let (mut old_r, mut r) = (a.clone(), b.clone());
+    let (mut old_s, mut s) = (BigInt::one(), BigInt::zero());
+    let (mut old_t, mut t) = (BigInt::zero(), BigInt::one());
+
+    while !r.is_zero() {
+        let q = &old_r / &r;
+        let new_r = &old_r - &q * &r;
+        old_r = std::mem::replace(&mut r, new_r);
+        let new_s = &old_s - &q * &s;
+        old_s = std::mem::replace(&mut s, new_s);
+        let new_t = &old_t - &q * &t;
+        old_t = std::mem::replace(&mut t, new_t);
+    }
+
+    (old_r, old_s, old_t)
+}
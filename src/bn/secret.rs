@@ -0,0 +1,64 @@
+use std::ops::Deref;
+
+use subtle::{Choice, ConstantTimeEq};
+
+use crate::bn::BigNumber;
+
+/// A [`BigNumber`] known to hold secret material (a master secret, a
+/// blinding factor, a private modulus factor, ...).
+///
+/// Wrapping a value in `SecretBigNumber` opts it into constant-time
+/// equality and scrubs the backing bytes when the value is dropped, without
+/// changing the fast, variable-time path `BigNumber` uses for public
+/// values. On the OpenSSL backend the digit buffer is cleared in place with
+/// `BN_clear` before it is freed; on the rust-bignum backend the value is
+/// reset to zero, but `num-bigint` doesn't expose its internal buffer, so
+/// the old digit buffer is released without being wiped.
+#[derive(Debug, Clone)]
+pub struct SecretBigNumber(BigNumber);
+
+impl SecretBigNumber {
+    pub fn new(value: BigNumber) -> SecretBigNumber {
+        SecretBigNumber(value)
+    }
+
+    /// Borrows the wrapped value for use in (non-secret-comparing) math
+    /// operations, e.g. `BigNumber::mod_exp`.
+    pub fn expose_secret(&self) -> &BigNumber {
+        &self.0
+    }
+}
+
+impl Deref for SecretBigNumber {
+    type Target = BigNumber;
+
+    fn deref(&self) -> &BigNumber {
+        &self.0
+    }
+}
+
+impl From<BigNumber> for SecretBigNumber {
+    fn from(value: BigNumber) -> SecretBigNumber {
+        SecretBigNumber::new(value)
+    }
+}
+
+impl ConstantTimeEq for SecretBigNumber {
+    fn ct_eq(&self, other: &SecretBigNumber) -> Choice {
+        self.0.ct_eq(&other.0).unwrap_or_else(|_| Choice::from(0))
+    }
+}
+
+impl PartialEq for SecretBigNumber {
+    fn eq(&self, other: &SecretBigNumber) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl Eq for SecretBigNumber {}
+
+impl Drop for SecretBigNumber {
+    fn drop(&mut self) {
+        self.0.zeroize();
+    }
+}
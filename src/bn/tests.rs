@@ -0,0 +1,77 @@
+//! Parity tests between the `openssl` and `rust-bignum` backends. Run with
+//! `cargo test --features "openssl rust-bignum"`.
+#[cfg(all(feature = "openssl", feature = "rust-bignum"))]
+mod backend_parity {
+    use crate::bn::backend::BigNumberBackend;
+    use crate::bn::openssl::OpensslBigNumber;
+    use crate::bn::rust::RustBigNumber;
+
+    #[test]
+    fn add_mul_and_mod_exp_agree() {
+        let a = "123456789012345678901234567890";
+        let b = "98765432109876543210";
+        let n = "1000000007";
+
+        let (oa, ob) = (
+            OpensslBigNumber::from_dec(a).unwrap(),
+            OpensslBigNumber::from_dec(b).unwrap(),
+        );
+        let (ra, rb) = (
+            RustBigNumber::from_dec(a).unwrap(),
+            RustBigNumber::from_dec(b).unwrap(),
+        );
+
+        assert_eq!(
+            oa.add(&ob).unwrap().to_dec().unwrap(),
+            ra.add(&rb).unwrap().to_dec().unwrap()
+        );
+        assert_eq!(
+            oa.mul(&ob).unwrap().to_dec().unwrap(),
+            ra.mul(&rb).unwrap().to_dec().unwrap()
+        );
+
+        let on = OpensslBigNumber::from_dec(n).unwrap();
+        let rn = RustBigNumber::from_dec(n).unwrap();
+        assert_eq!(
+            oa.mod_exp(&ob, &on).unwrap().to_dec().unwrap(),
+            ra.mod_exp(&rb, &rn).unwrap().to_dec().unwrap()
+        );
+    }
+
+    #[test]
+    fn gcd_agrees() {
+        let (a_o, b_o) = (
+            OpensslBigNumber::from_dec("270").unwrap(),
+            OpensslBigNumber::from_dec("192").unwrap(),
+        );
+        let (a_r, b_r) = (
+            RustBigNumber::from_dec("270").unwrap(),
+            RustBigNumber::from_dec("192").unwrap(),
+        );
+
+        assert_eq!(
+            OpensslBigNumber::gcd(&a_o, &b_o).unwrap().to_dec().unwrap(),
+            RustBigNumber::gcd(&a_r, &b_r).unwrap().to_dec().unwrap()
+        );
+    }
+
+    #[test]
+    fn negative_mod_exp_agrees() {
+        let base = "7";
+        let exp = "-3";
+        let n = "41";
+
+        let ob = OpensslBigNumber::from_dec(base).unwrap();
+        let oe = OpensslBigNumber::from_dec(exp).unwrap();
+        let on = OpensslBigNumber::from_dec(n).unwrap();
+
+        let rb = RustBigNumber::from_dec(base).unwrap();
+        let re = RustBigNumber::from_dec(exp).unwrap();
+        let rn = RustBigNumber::from_dec(n).unwrap();
+
+        assert_eq!(
+            ob.mod_exp(&oe, &on).unwrap().to_dec().unwrap(),
+            rb.mod_exp(&re, &rn).unwrap().to_dec().unwrap()
+        );
+    }
+}